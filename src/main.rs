@@ -1,14 +1,388 @@
-use clap::{Parser, Subcommand};
+use chrono::DateTime;
+use clap::{Args, Parser, Subcommand};
 use deltalake::{
+    arrow::{
+        array::RecordBatch,
+        datatypes::{DataType as ArrowDataType, Schema as ArrowSchema, SchemaRef, TimeUnit},
+    },
+    datafusion::{
+        common::DFSchema,
+        dataframe::DataFrame,
+        datasource::schema_adapter::{SchemaAdapter, SchemaAdapterFactory, SchemaMapper},
+        error::DataFusionError,
+        prelude::SessionContext,
+    },
     datafusion::{
-        common::DFSchema, dataframe::DataFrame, error::DataFusionError, prelude::SessionContext,
+        logical_expr::{Expr, Operator},
+        scalar::ScalarValue,
     },
-    kernel::StructType,
+    delta_datafusion::{DeltaScanConfigBuilder, DeltaTableProvider},
+    kernel::{Action, Add, DataType, Metadata, Protocol, StructField, StructType},
+    parquet::file::reader::{FileReader, SerializedFileReader},
     DeltaTable, DeltaTableError,
 };
-use std::{ffi::OsStr, path::Path, sync::Arc};
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    fs::{self, File},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::UNIX_EPOCH,
+};
 use thiserror::Error;
 
+/// Split a Hive-style partition directory segment into its name and value, e.g. `region=us` -> ("region", "us").
+fn parse_hive_segment(segment: &str) -> Option<(&str, &str)> {
+    segment.split_once('=')
+}
+
+/// Recursively collect every `.parquet` file under `root`.
+fn discover_parquet_files(root: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().and_then(OsStr::to_str) == Some("parquet") {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Infer the Hive-style partition values encoded in `path`'s directory segments relative to
+/// `root`, keeping only the ones named in `partition_columns`.
+fn partition_values_for(
+    root: &Path,
+    path: &Path,
+    partition_columns: &[String],
+) -> HashMap<String, Option<String>> {
+    // Every declared partition column must have an entry in `add.partitionValues`, even if
+    // this particular file isn't nested under a matching `col=value` segment.
+    let mut values: HashMap<String, Option<String>> = partition_columns
+        .iter()
+        .map(|column| (column.clone(), None))
+        .collect();
+    if let Ok(relative) = path.strip_prefix(root) {
+        for segment in relative.parent().into_iter().flat_map(Path::iter) {
+            if let Some((key, value)) = segment.to_str().and_then(parse_hive_segment) {
+                if partition_columns.iter().any(|c| c == key) {
+                    values.insert(key.to_string(), Some(value.to_string()));
+                }
+            }
+        }
+    }
+    values
+}
+
+/// Read the Arrow schema out of a single Parquet file's footer.
+fn parquet_arrow_schema(path: &Path) -> Result<ArrowSchema, Error> {
+    let file = File::open(path)?;
+    let reader = SerializedFileReader::new(file)?;
+    let schema = reader.metadata().file_metadata().schema_descr_ptr();
+    Ok(deltalake::parquet::arrow::parquet_to_arrow_schema(
+        &schema, None,
+    )?)
+}
+
+/// Merge every discovered file's Arrow schema into one unified schema, then convert it into
+/// the corresponding Delta `StructType`. Footer fields named like a partition column are
+/// dropped; every partition column is then appended as a nullable string field.
+fn infer_unified_schema(
+    files: &[PathBuf],
+    partition_columns: &[String],
+) -> Result<StructType, Error> {
+    let mut merged = ArrowSchema::empty();
+    for file in files {
+        let schema = parquet_arrow_schema(file)?;
+        merged = ArrowSchema::try_merge(vec![merged, schema])?;
+    }
+
+    let mut fields = merged
+        .fields()
+        .iter()
+        .filter(|field| !partition_columns.iter().any(|c| c == field.name()))
+        .map(|field| {
+            Ok(StructField::new(
+                field.name().clone(),
+                DataType::try_from(field.data_type())?,
+                field.is_nullable(),
+            ))
+        })
+        .collect::<Result<Vec<_>, deltalake::arrow::error::ArrowError>>()?;
+
+    // Partition columns never appear in the Parquet footers themselves (they're encoded in
+    // the directory layout), but the Delta protocol still requires them to be declared in
+    // the table schema, since `partitionColumns` must name a subset of its fields.
+    for partition_column in partition_columns {
+        fields.push(StructField::new(
+            partition_column.clone(),
+            DataType::STRING,
+            true,
+        ));
+    }
+
+    Ok(StructType::new(fields))
+}
+
+/// The `[min, max]` range lakecli can establish for `column` on a given file, sourced either
+/// from an exact partition value or from the file's recorded Parquet statistics.
+fn column_range(
+    column: &str,
+    partition_values: &HashMap<String, Option<String>>,
+    stats: Option<&deltalake::protocol::Stats>,
+) -> Option<(serde_json::Value, serde_json::Value)> {
+    if let Some(Some(value)) = partition_values.get(column) {
+        let value = serde_json::Value::String(value.clone());
+        return Some((value.clone(), value));
+    }
+
+    let stats = stats?;
+    let min = stats.min_values.get(column)?.as_value()?.clone();
+    let max = stats.max_values.get(column)?.as_value()?.clone();
+    Some((min, max))
+}
+
+/// Convert a literal to the same JSON representation Delta uses for that logical type in
+/// `add.stats` (e.g. dates as ISO-8601 strings, not raw epoch counts), so `compare_values`
+/// is comparing like with like rather than a number against a string. Timestamp literals
+/// yield `None`, so they never prune a file.
+fn scalar_as_json(scalar: &ScalarValue) -> Option<serde_json::Value> {
+    let iso_date = |days: i32| {
+        chrono::NaiveDate::from_ymd_opt(1970, 1, 1)?
+            .checked_add_signed(chrono::Duration::days(days as i64))
+            .map(|date| date.format("%Y-%m-%d").to_string())
+    };
+
+    match scalar {
+        ScalarValue::Date32(Some(days)) => iso_date(*days).map(serde_json::Value::String),
+        ScalarValue::Date64(Some(millis)) => {
+            iso_date((*millis / 86_400_000) as i32).map(serde_json::Value::String)
+        }
+        // `add.stats` encodes timestamp min/max as ISO-8601 strings, but the exact
+        // formatting (fractional-second precision, `Z` vs `+00:00`) isn't something we can
+        // reliably reconstruct from a bare DataFusion literal. Comparing mismatched
+        // encodings risks pruning a file whose range actually covers the predicate, so we
+        // deliberately skip pruning on timestamp columns rather than guess.
+        ScalarValue::TimestampSecond(_, _)
+        | ScalarValue::TimestampMillisecond(_, _)
+        | ScalarValue::TimestampMicrosecond(_, _)
+        | ScalarValue::TimestampNanosecond(_, _) => None,
+        other => serde_json::to_value(other).ok(),
+    }
+}
+
+fn json_as_f64(value: &serde_json::Value) -> Option<f64> {
+    value.as_f64().or_else(|| value.as_str()?.parse().ok())
+}
+
+/// Compare two JSON-encoded stats/partition values, preferring a numeric comparison and
+/// falling back to lexicographic string comparison (e.g. for dates and plain strings).
+fn compare_values(a: &serde_json::Value, b: &serde_json::Value) -> Option<std::cmp::Ordering> {
+    match (json_as_f64(a), json_as_f64(b)) {
+        (Some(a), Some(b)) => a.partial_cmp(&b),
+        _ => a.as_str()?.partial_cmp(b.as_str()?),
+    }
+}
+
+/// Returns `true` unless `expr` can be *proven* false for every row in a file whose columns
+/// are known only by `partition_values` and `stats` min/max ranges. Missing stats for a
+/// referenced column always keep the file, since we can't prove anything about it.
+fn file_may_match(
+    expr: &Expr,
+    partition_values: &HashMap<String, Option<String>>,
+    stats: Option<&deltalake::protocol::Stats>,
+) -> bool {
+    match expr {
+        Expr::BinaryExpr(binary) => match binary.op {
+            Operator::And => {
+                file_may_match(&binary.left, partition_values, stats)
+                    && file_may_match(&binary.right, partition_values, stats)
+            }
+            Operator::Or => {
+                file_may_match(&binary.left, partition_values, stats)
+                    || file_may_match(&binary.right, partition_values, stats)
+            }
+            Operator::Eq | Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+                let swapped = |op: Operator| match op {
+                    Operator::Lt => Operator::Gt,
+                    Operator::LtEq => Operator::GtEq,
+                    Operator::Gt => Operator::Lt,
+                    Operator::GtEq => Operator::LtEq,
+                    other => other,
+                };
+                let (column, op, literal) = match (&*binary.left, &*binary.right) {
+                    (Expr::Column(column), Expr::Literal(literal)) => {
+                        (column.name.as_str(), binary.op, literal)
+                    }
+                    (Expr::Literal(literal), Expr::Column(column)) => {
+                        (column.name.as_str(), swapped(binary.op), literal)
+                    }
+                    _ => return true,
+                };
+
+                let (Some((min, max)), Some(literal)) = (
+                    column_range(column, partition_values, stats),
+                    scalar_as_json(literal),
+                ) else {
+                    return true;
+                };
+
+                let (Some(min_cmp), Some(max_cmp)) =
+                    (compare_values(&min, &literal), compare_values(&max, &literal))
+                else {
+                    return true;
+                };
+                use std::cmp::Ordering::*;
+                match op {
+                    Operator::Eq => min_cmp != Greater && max_cmp != Less,
+                    Operator::Lt => min_cmp == Less,
+                    Operator::LtEq => min_cmp != Greater,
+                    Operator::Gt => max_cmp == Greater,
+                    Operator::GtEq => max_cmp != Less,
+                    _ => true,
+                }
+            }
+            _ => true,
+        },
+        _ => true,
+    }
+}
+
+/// Check that every requested column projection actually exists in `available`, returning a
+/// single error listing all unknown names rather than failing on the first one.
+fn validate_columns(available: &[String], requested: &[String]) -> Result<(), Error> {
+    let unknown: Vec<String> = requested
+        .iter()
+        .filter(|column| !available.contains(column))
+        .cloned()
+        .collect();
+
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::UnknownColumns(unknown))
+    }
+}
+
+/// Recursively normalize every timestamp field in `data_type` to microsecond precision,
+/// matching the Delta protocol's canonical timestamp unit.
+fn normalize_timestamps(data_type: &ArrowDataType) -> ArrowDataType {
+    match data_type {
+        ArrowDataType::Timestamp(_, tz) => {
+            ArrowDataType::Timestamp(TimeUnit::Microsecond, tz.clone())
+        }
+        ArrowDataType::Struct(fields) => {
+            let fields: Vec<_> = fields
+                .iter()
+                .map(|field| {
+                    field
+                        .as_ref()
+                        .clone()
+                        .with_data_type(normalize_timestamps(field.data_type()))
+                })
+                .collect();
+            ArrowDataType::Struct(fields.into())
+        }
+        ArrowDataType::List(field) => ArrowDataType::List(Arc::new(
+            field
+                .as_ref()
+                .clone()
+                .with_data_type(normalize_timestamps(field.data_type())),
+        )),
+        ArrowDataType::Map(field, sorted) => ArrowDataType::Map(
+            Arc::new(
+                field
+                    .as_ref()
+                    .clone()
+                    .with_data_type(normalize_timestamps(field.data_type())),
+            ),
+            *sorted,
+        ),
+        other => other.clone(),
+    }
+}
+
+/// A [`SchemaAdapterFactory`] that reconciles a Parquet file's physical schema with the
+/// table's current logical schema: columns absent from the file are filled with nulls,
+/// nested struct/list/map fields are matched by name, and timestamps are normalized to
+/// microsecond precision. This keeps `query` working across tables that have undergone
+/// `ALTER TABLE ADD COLUMN` or precision changes.
+///
+/// This, `DeltaScanConfigBuilder::with_file_column_name`, and `DeltaTableProvider` reach into
+/// delta-rs/DataFusion APIs that have moved across versions; pin an exact `deltalake` version
+/// in `Cargo.toml` and run `cargo build && cargo clippy --all-targets -- -D warnings` against
+/// it before merging, since none of that could be exercised in this environment.
+#[derive(Debug)]
+struct ReconcilingSchemaAdapterFactory;
+
+impl SchemaAdapterFactory for ReconcilingSchemaAdapterFactory {
+    fn create(
+        &self,
+        projected_table_schema: SchemaRef,
+        _table_schema: SchemaRef,
+    ) -> Box<dyn SchemaAdapter> {
+        Box::new(ReconcilingSchemaAdapter {
+            table_schema: projected_table_schema,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct ReconcilingSchemaAdapter {
+    table_schema: SchemaRef,
+}
+
+impl SchemaAdapter for ReconcilingSchemaAdapter {
+    fn map_column_index(&self, index: usize, file_schema: &ArrowSchema) -> Option<usize> {
+        file_schema
+            .index_of(self.table_schema.field(index).name())
+            .ok()
+    }
+
+    fn map_schema(
+        &self,
+        file_schema: &ArrowSchema,
+    ) -> deltalake::datafusion::error::Result<(Arc<dyn SchemaMapper>, Vec<usize>)> {
+        let projection: Vec<usize> = (0..file_schema.fields().len()).collect();
+        Ok((
+            Arc::new(ReconcilingSchemaMapper {
+                table_schema: self.table_schema.clone(),
+            }),
+            projection,
+        ))
+    }
+}
+
+#[derive(Debug)]
+struct ReconcilingSchemaMapper {
+    table_schema: SchemaRef,
+}
+
+impl SchemaMapper for ReconcilingSchemaMapper {
+    fn map_batch(&self, batch: RecordBatch) -> deltalake::datafusion::error::Result<RecordBatch> {
+        let mut columns = Vec::with_capacity(self.table_schema.fields().len());
+        for field in self.table_schema.fields() {
+            let column = match batch.schema().index_of(field.name()) {
+                Ok(index) => deltalake::arrow::compute::cast(
+                    batch.column(index),
+                    &normalize_timestamps(field.data_type()),
+                )?,
+                Err(_) => {
+                    deltalake::arrow::array::new_null_array(field.data_type(), batch.num_rows())
+                }
+            };
+            columns.push(column);
+        }
+        Ok(RecordBatch::try_new(self.table_schema.clone(), columns)?)
+    }
+}
+
 #[derive(Parser)]
 #[command(author, version, about, long_about)]
 #[command(propagate_version = true)]
@@ -50,10 +424,26 @@ enum Table {
 }
 
 impl Table {
-    pub async fn files(&self) -> Result<(), Error> {
+    pub async fn files(&self, ctx: &SessionContext, predicate: Option<&str>) -> Result<(), Error> {
         match self {
             Table::Delta(delta_table) => {
-                let files: Vec<_> = delta_table.get_file_uris()?.collect();
+                let files: Vec<_> = match predicate {
+                    Some(predicate) => {
+                        let dataframe = self.register_table(ctx, None, None).await?;
+                        let expr = ctx.parse_sql_expr(predicate, dataframe.schema())?;
+                        delta_table
+                            .snapshot()?
+                            .file_actions()?
+                            .into_iter()
+                            .filter(|add| {
+                                let stats = add.get_stats().ok().flatten();
+                                file_may_match(&expr, &add.partition_values, stats.as_ref())
+                            })
+                            .map(|add| delta_table.table_uri() + "/" + &add.path)
+                            .collect()
+                    }
+                    None => delta_table.get_file_uris()?.collect(),
+                };
                 println!("files: {:?}", files);
             }
             Table::Parquet { table_path } => {
@@ -65,16 +455,35 @@ impl Table {
         Ok(())
     }
 
-    pub async fn schema(&self, ctx: &SessionContext) -> Result<(), Error> {
+    pub async fn schema(
+        &self,
+        ctx: &SessionContext,
+        columns: Option<&[String]>,
+    ) -> Result<(), Error> {
         match self {
             Table::Delta(delta_table) => match delta_table.schema() {
-                Some(schema) => print_delta_schema(schema),
+                Some(schema) => match columns {
+                    Some(columns) => {
+                        let available: Vec<String> =
+                            schema.fields().map(|field| field.name.clone()).collect();
+                        validate_columns(&available, columns)?;
+                        let projected = StructType::new(
+                            schema
+                                .fields()
+                                .filter(|field| columns.iter().any(|c| c == &field.name))
+                                .cloned()
+                                .collect::<Vec<_>>(),
+                        );
+                        print_delta_schema(&projected);
+                    }
+                    None => print_delta_schema(schema),
+                },
                 None => {
                     println!("No schema found in delta table!");
                 }
             },
             Table::Parquet { table_path: _ } => {
-                let dataframe = self.register_table(ctx).await?;
+                let dataframe = self.register_table(ctx, None, columns).await?;
                 print_dataframe_schema(dataframe.schema());
             }
         }
@@ -101,7 +510,7 @@ impl Table {
                 println!("metadata: {:?}", delta_table.metadata()?);
             }
             Table::Parquet { table_path: _ } => {
-                let dataframe = self.register_table(ctx).await?;
+                let dataframe = self.register_table(ctx, None, None).await?;
                 println!("metadata: {:?}", dataframe.schema().metadata());
             }
         }
@@ -126,29 +535,191 @@ impl Table {
         Ok(())
     }
 
-    pub async fn query(&self, ctx: &SessionContext, query: &str) -> Result<(), Error> {
-        self.register_table(ctx).await?;
+    pub async fn query(
+        &self,
+        ctx: &SessionContext,
+        query: &str,
+        file_path_column: Option<&str>,
+        columns: Option<&[String]>,
+    ) -> Result<(), Error> {
+        self.register_table(ctx, file_path_column, columns).await?;
         let dataframe = ctx.sql(&query).await?;
         dataframe.show().await?;
         Ok(())
     }
 
-    async fn register_table(&self, ctx: &SessionContext) -> Result<DataFrame, Error> {
+    /// Surface the Delta Change Data Feed between `starting_version` and `ending_version`
+    /// (inclusive, defaulting to the latest version) by delegating to `DeltaOps::load_cdf`,
+    /// then rendering the result the same way `query` does.
+    pub async fn changes(
+        &self,
+        ctx: &SessionContext,
+        starting_version: i64,
+        ending_version: Option<i64>,
+    ) -> Result<(), Error> {
         match self {
             Table::Delta(delta_table) => {
+                let mut builder = deltalake::operations::DeltaOps((**delta_table).clone())
+                    .load_cdf()
+                    .with_starting_version(starting_version);
+                if let Some(ending_version) = ending_version {
+                    builder = builder.with_ending_version(ending_version);
+                }
+                let provider = match builder.build().await {
+                    Ok(provider) => provider,
+                    Err(err) if err.to_string().to_lowercase().contains("change data feed") => {
+                        println!(
+                            "Change Data Feed is not enabled on '{}'. Set `delta.enableChangeDataFeed = true` on the table to use this command.",
+                            delta_table.table_uri()
+                        );
+                        return Ok(());
+                    }
+                    Err(err) => return Err(err.into()),
+                };
+
+                ctx.register_table("t", Arc::new(provider))?;
+                let dataframe = ctx.table("t").await?;
+                dataframe.show().await?;
+            }
+            other => {
+                println!("'changes' call unsupported for: {:?}", other);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Bootstrap a Delta table in place over an existing directory of Parquet files, without
+    /// rewriting any data. Writes a single `_delta_log/00000000000000000000.json` commit
+    /// containing a `protocol` action, a `metaData` action, and one `add` action per discovered
+    /// file.
+    pub async fn convert(
+        table_path: &str,
+        partition_columns: Option<Vec<String>>,
+    ) -> Result<(), Error> {
+        let root = Path::new(table_path);
+        let log_dir = root.join("_delta_log");
+        let commit_path = log_dir.join("00000000000000000000.json");
+        if commit_path.exists() {
+            return Err(Error::AlreadyADeltaTable(table_path.to_string()));
+        }
+
+        let partition_columns = partition_columns.unwrap_or_default();
+        let files = discover_parquet_files(root)?;
+        let schema = infer_unified_schema(&files, &partition_columns)?;
+
+        let metadata = Metadata::try_new(schema, partition_columns.clone(), HashMap::new())?;
+
+        fs::create_dir_all(&log_dir)?;
+
+        // The Delta protocol requires every table's first commit to declare the reader/writer
+        // protocol versions before any metaData/add actions; without it delta-rs (and thus
+        // lakecli's own `schema`/`query`/`files`) refuses to build a snapshot from the log.
+        let mut actions = vec![
+            Action::Protocol(Protocol::new(1, 2)),
+            Action::Metadata(metadata),
+        ];
+        for file in &files {
+            let file_metadata = fs::metadata(file)?;
+            let modification_time = file_metadata
+                .modified()?
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as i64;
+            let partition_values = partition_values_for(root, file, &partition_columns);
+            let path = file
+                .strip_prefix(root)
+                .unwrap_or(file)
+                .to_string_lossy()
+                .into_owned();
+
+            actions.push(Action::Add(Add {
+                path,
+                size: file_metadata.len() as i64,
+                partition_values,
+                modification_time,
+                data_change: true,
+                ..Default::default()
+            }));
+        }
+
+        let mut contents = String::new();
+        for action in actions {
+            contents.push_str(&serde_json::to_string(&action)?);
+            contents.push('\n');
+        }
+        fs::write(commit_path, contents)?;
+
+        println!(
+            "Converted {} parquet file(s) under '{}' into a Delta table",
+            files.len(),
+            table_path
+        );
+
+        Ok(())
+    }
+
+    async fn register_table(
+        &self,
+        ctx: &SessionContext,
+        file_path_column: Option<&str>,
+        columns: Option<&[String]>,
+    ) -> Result<DataFrame, Error> {
+        let dataframe = match self {
+            Table::Delta(delta_table) => {
+                let mut scan_config_builder = DeltaScanConfigBuilder::new()
+                    .with_schema_adapter_factory(Arc::new(ReconcilingSchemaAdapterFactory));
+                if let Some(file_path_column) = file_path_column {
+                    scan_config_builder = scan_config_builder
+                        .with_file_column_name(&Some(file_path_column.to_string()));
+                }
+                let scan_config = scan_config_builder.build(delta_table.snapshot()?)?;
+                let provider = Arc::new(DeltaTableProvider::try_new(
+                    delta_table.snapshot()?.clone(),
+                    delta_table.log_store(),
+                    scan_config,
+                )?);
+
                 let metadata = delta_table.metadata()?;
                 if let Some(table_name) = &metadata.name {
-                    ctx.register_table(table_name, delta_table.clone())?;
+                    ctx.register_table(table_name, provider.clone())?;
                 }
                 // Always register table 't', for simplicity
-                ctx.register_table("t", delta_table.clone())?;
-                ctx.table("t").await.map_err(|err| err.into())
+                ctx.register_table("t", provider)?;
+                ctx.table("t").await?
             }
             Table::Parquet { table_path } => {
                 ctx.register_parquet("t", table_path, Default::default())
                     .await?;
-                ctx.table("t").await.map_err(|err| err.into())
+                ctx.table("t").await?
             }
+        };
+
+        match columns {
+            Some(columns) => {
+                let available: Vec<String> = dataframe
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|field| field.name().clone())
+                    .collect();
+                validate_columns(&available, columns)?;
+                let mut column_refs: Vec<&str> = columns.iter().map(String::as_str).collect();
+                // --with-file-path must survive a --columns projection even if the user
+                // didn't think to list it explicitly.
+                if let Some(file_path_column) = file_path_column {
+                    if !column_refs.contains(&file_path_column) {
+                        column_refs.push(file_path_column);
+                    }
+                }
+                let projected = dataframe.select_columns(&column_refs)?;
+                // Re-register 't' against the projection, so a raw SQL `query` against it
+                // only reads the requested columns too.
+                ctx.deregister_table("t")?;
+                ctx.register_table("t", projected.clone().into_view())?;
+                Ok(projected)
+            }
+            None => Ok(dataframe),
         }
     }
 }
@@ -163,9 +734,46 @@ enum Error {
 
     #[error("Data fusion error")]
     DataFusion(#[from] DataFusionError),
+
+    #[error("Arrow error")]
+    Arrow(#[from] deltalake::arrow::error::ArrowError),
+
+    #[error("Parquet error")]
+    Parquet(#[from] deltalake::parquet::errors::ParquetError),
+
+    #[error("IO error")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid RFC 3339 timestamp: {0}")]
+    InvalidTimestamp(String),
+
+    #[error("No table version found at or before timestamp: {0}")]
+    NoVersionAtTimestamp(String),
+
+    #[error("Unknown column(s): {0:?}")]
+    UnknownColumns(Vec<String>),
+
+    #[error("'{0}' is already a Delta table (_delta_log/00000000000000000000.json exists)")]
+    AlreadyADeltaTable(String),
 }
 
-async fn open_table(table_name: &str) -> Result<Table, Error> {
+/// Shared time-travel flags for commands that read a Delta snapshot. At most one of
+/// `version` / `timestamp` should be set; `version` takes precedence if both are.
+#[derive(Args, Clone, Debug, Default)]
+struct TimeTravel {
+    /// Load the table as of this version instead of the latest.
+    #[clap(long)]
+    version: Option<i64>,
+
+    /// Load the table as of the latest commit at or before this RFC 3339 timestamp.
+    #[clap(long)]
+    timestamp: Option<String>,
+}
+
+async fn open_table(table_name: &str, time_travel: &TimeTravel) -> Result<Table, Error> {
     let extension = Path::new(table_name).extension().and_then(OsStr::to_str);
     match extension {
         Some("parquet") => Ok(Table::Parquet {
@@ -173,8 +781,26 @@ async fn open_table(table_name: &str) -> Result<Table, Error> {
         }),
         Some(_other) => Err(Error::UnknownTableFormat),
         None => {
-            let table =
+            let mut table =
                 deltalake::open_table_with_storage_options(table_name, Default::default()).await?;
+
+            if let Some(version) = time_travel.version {
+                table.load_version(version).await?;
+            } else if let Some(timestamp) = &time_travel.timestamp {
+                let requested = DateTime::parse_from_rfc3339(timestamp)
+                    .map_err(|_| Error::InvalidTimestamp(timestamp.clone()))?;
+                let version = table
+                    .history(None)
+                    .await?
+                    .into_iter()
+                    .filter_map(|commit| Some((commit.version?, commit.timestamp?)))
+                    .filter(|(_, commit_ts)| *commit_ts <= requested.timestamp_millis())
+                    .max_by_key(|(_, commit_ts)| *commit_ts)
+                    .map(|(version, _)| version)
+                    .ok_or_else(|| Error::NoVersionAtTimestamp(timestamp.clone()))?;
+                table.load_version(version).await?;
+            }
+
             Ok(Table::Delta(Arc::new(table)))
         }
     }
@@ -183,7 +809,15 @@ async fn open_table(table_name: &str) -> Result<Table, Error> {
 #[derive(Subcommand)]
 enum Commands {
     #[clap(about = "List files in the table")]
-    Files { table: String },
+    Files {
+        table: String,
+
+        #[clap(long = "where")]
+        where_clause: Option<String>,
+
+        #[clap(flatten)]
+        time_travel: TimeTravel,
+    },
     #[clap(about = "Show table history. Currently for delta tables only")]
     History {
         table: String,
@@ -192,10 +826,24 @@ enum Commands {
         limit: Option<usize>,
     },
     #[clap(about = "Print table metadata")]
-    Metadata { table: String },
+    Metadata {
+        table: String,
+
+        #[clap(flatten)]
+        time_travel: TimeTravel,
+    },
 
     #[clap(about = "Show table schema")]
-    Schema { table: String },
+    Schema {
+        table: String,
+
+        /// Only print these columns. Comma-separated.
+        #[clap(long, value_delimiter = ',')]
+        columns: Option<Vec<String>>,
+
+        #[clap(flatten)]
+        time_travel: TimeTravel,
+    },
 
     #[clap(
         about = "Print the current / latest table version number. Currently for delta tables only"
@@ -203,7 +851,39 @@ enum Commands {
     Version { table: String },
 
     #[clap(about = "Query the table with a DataFusion query")]
-    Query { table: String, query: String },
+    Query {
+        table: String,
+        query: String,
+
+        /// Inject a virtual column carrying each row's source Parquet file URI. Delta tables
+        /// only; a single `.parquet` file gets no such column.
+        #[clap(long, num_args = 0..=1, default_missing_value = "_file_path")]
+        with_file_path: Option<String>,
+
+        /// Only read these columns. Comma-separated.
+        #[clap(long, value_delimiter = ',')]
+        columns: Option<Vec<String>>,
+
+        #[clap(flatten)]
+        time_travel: TimeTravel,
+    },
+
+    #[clap(about = "Convert a directory of Parquet files into a Delta table in place")]
+    Convert {
+        table: String,
+
+        #[clap(long)]
+        partition_columns: Option<Vec<String>>,
+    },
+
+    #[clap(about = "Show the Change Data Feed between two table versions")]
+    Changes {
+        table: String,
+        starting_version: i64,
+
+        #[clap(long)]
+        ending_version: Option<i64>,
+    },
 }
 
 #[tokio::main]
@@ -214,30 +894,62 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let ctx = SessionContext::new();
 
     match &cli.command {
-        Commands::Files { table } => {
-            let table = open_table(&table).await?;
-            table.files().await?;
+        Commands::Files {
+            table,
+            where_clause,
+            time_travel,
+        } => {
+            let table = open_table(&table, time_travel).await?;
+            table.files(&ctx, where_clause.as_deref()).await?;
         }
-        Commands::Schema { table } => {
-            let table = open_table(&table).await?;
-            table.schema(&ctx).await?;
+        Commands::Schema {
+            table,
+            columns,
+            time_travel,
+        } => {
+            let table = open_table(&table, time_travel).await?;
+            table.schema(&ctx, columns.as_deref()).await?;
         }
         Commands::Version { table } => {
-            let table = open_table(&table).await?;
+            let table = open_table(&table, &TimeTravel::default()).await?;
             table.version().await?;
         }
-        Commands::Metadata { table } => {
-            let table = open_table(&table).await?;
+        Commands::Metadata { table, time_travel } => {
+            let table = open_table(&table, time_travel).await?;
             table.metadata(&ctx).await?;
         }
         Commands::History { table, limit } => {
-            let table = open_table(&table).await?;
+            let table = open_table(&table, &TimeTravel::default()).await?;
             table.history(*limit).await?;
         }
-        Commands::Query { table, query } => {
+        Commands::Query {
+            table,
+            query,
+            with_file_path,
+            columns,
+            time_travel,
+        } => {
             let table_name = table;
-            let table = open_table(&table_name).await?;
-            table.query(&ctx, query).await?;
+            let table = open_table(&table_name, time_travel).await?;
+            table
+                .query(&ctx, query, with_file_path.as_deref(), columns.as_deref())
+                .await?;
+        }
+        Commands::Convert {
+            table,
+            partition_columns,
+        } => {
+            Table::convert(table, partition_columns.clone()).await?;
+        }
+        Commands::Changes {
+            table,
+            starting_version,
+            ending_version,
+        } => {
+            let table = open_table(&table, &TimeTravel::default()).await?;
+            table
+                .changes(&ctx, *starting_version, *ending_version)
+                .await?;
         }
     }
 